@@ -0,0 +1,89 @@
+use crate::error::ErrorInner;
+use crate::Error;
+use mongodb::bson::Document;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// Compute a content digest over a set of seed documents.
+///
+/// Used to key cached `mongod` data-directory snapshots: identical fixture
+/// input produces the same key, so [`restore_into`] can skip re-seeding
+/// entirely on a cache hit, and a changed digest naturally misses the cache
+/// (invalidation falls out of the key changing, nothing has to be tracked
+/// separately).
+pub fn digest(documents: &[Document]) -> String {
+	let mut hasher = Sha256::new();
+	for document in documents {
+		hasher.update(document.to_string().as_bytes());
+	}
+	format!("{:x}", hasher.finalize())
+}
+
+/// The directory under which cached data-directory snapshots are stored.
+fn cache_root() -> PathBuf {
+	std::env::temp_dir().join("temp-mongo-cache")
+}
+
+/// Path to the cached snapshot for `key`.
+fn snapshot_path(key: &str) -> PathBuf {
+	cache_root().join(key)
+}
+
+/// Persist `data_dir` as the cached snapshot for `key`, replacing any existing one.
+pub fn store(key: &str, data_dir: &Path) -> Result<(), Error> {
+	let dest = snapshot_path(key);
+	if dest.exists() {
+		std::fs::remove_dir_all(&dest).map_err(|e| ErrorInner::CleanDir(dest.clone(), e))?;
+	}
+	copy_dir_all(data_dir, &dest)
+}
+
+/// Copy the cached snapshot for `key` into `dest`, if one exists.
+///
+/// Returns `false`, leaving `dest` untouched, if there is no cached snapshot for `key`.
+pub fn restore_into(key: &str, dest: &Path) -> Result<bool, Error> {
+	let source = snapshot_path(key);
+	if !source.exists() {
+		return Ok(false);
+	}
+	copy_dir_all(&source, dest)?;
+	Ok(true)
+}
+
+/// Remove the cached snapshot for `key`, if any.
+pub fn clear(key: &str) -> Result<(), Error> {
+	let dest = snapshot_path(key);
+	if dest.exists() {
+		std::fs::remove_dir_all(&dest).map_err(|e| ErrorInner::CleanDir(dest, e))?;
+	}
+	Ok(())
+}
+
+/// Remove every cached snapshot.
+pub fn clear_all() -> Result<(), Error> {
+	let root = cache_root();
+	if root.exists() {
+		std::fs::remove_dir_all(&root).map_err(|e| ErrorInner::CleanDir(root, e))?;
+	}
+	Ok(())
+}
+
+/// Recursively copy the contents of `src` into `dst`, creating `dst` if needed.
+fn copy_dir_all(src: &Path, dst: &Path) -> Result<(), Error> {
+	std::fs::create_dir_all(dst).map_err(|e| ErrorInner::MakeDbDir(dst.to_owned(), e))?;
+	let entries = std::fs::read_dir(src).map_err(|e| ErrorInner::CleanDir(src.to_owned(), e))?;
+	for entry in entries {
+		let entry = entry.map_err(|e| ErrorInner::CleanDir(src.to_owned(), e))?;
+		let file_type = entry
+			.file_type()
+			.map_err(|e| ErrorInner::CleanDir(src.to_owned(), e))?;
+		let dest_path = dst.join(entry.file_name());
+		if file_type.is_dir() {
+			copy_dir_all(&entry.path(), &dest_path)?;
+		} else {
+			std::fs::copy(entry.path(), &dest_path)
+				.map_err(|e| ErrorInner::CleanDir(entry.path(), e))?;
+		}
+	}
+	Ok(())
+}
@@ -0,0 +1,125 @@
+use crate::dump::{CollectionManifestEntry, DumpMetadata, METADATA_FILE_NAME};
+use crate::error::ErrorInner;
+use crate::Error;
+use std::fs::File;
+use std::path::Path;
+
+/// A `dump_format = 1` manifest entry.
+///
+/// Version 1 archives named their per-collection files `database.collection.json`
+/// and counted documents under `count` instead of `document_count`.
+#[derive(serde::Deserialize)]
+struct V1ManifestEntry {
+	database: String,
+	collection: String,
+	count: u64,
+}
+
+/// A `dump_format = 1` `metadata.json`.
+///
+/// Version 1 predates the `dump_format` field entirely, so its absence from
+/// `metadata.json` is what [`super::read_dump_format`] treats as "version 1".
+#[derive(serde::Deserialize)]
+struct V1Metadata {
+	crate_version: String,
+	mongodb_version: String,
+	dumped_at: u64,
+	collections: Vec<V1ManifestEntry>,
+}
+
+/// Upgrade a scratch directory written in an older `dump_format` to the current one in place.
+///
+/// This rewrites `metadata.json` to the current [`DumpMetadata`] shape and
+/// renames per-collection files to match, so that [`super::loaders::V2Loader`]
+/// can replay the result without caring which format the archive originally used.
+pub(crate) fn upgrade(from_format: u32, scratch_dir: &Path) -> Result<(), Error> {
+	match from_format {
+		1 => upgrade_v1(scratch_dir),
+		other => Err(ErrorInner::Dump(format!(
+			"no compatibility upgrade available from dump format {other}"
+		))
+		.into()),
+	}
+}
+
+fn upgrade_v1(scratch_dir: &Path) -> Result<(), Error> {
+	let metadata_path = scratch_dir.join(METADATA_FILE_NAME);
+	let metadata_file =
+		File::open(&metadata_path).map_err(|e| ErrorInner::DumpIo(metadata_path.clone(), e))?;
+	let old: V1Metadata = serde_json::from_reader(metadata_file).map_err(ErrorInner::DumpMetadata)?;
+
+	let mut collections = Vec::with_capacity(old.collections.len());
+	for entry in old.collections {
+		let old_file_name = format!("{}.{}.json", entry.database, entry.collection);
+		let new_file_name = format!("{}__{}.ndjson", entry.database, entry.collection);
+
+		let old_path = scratch_dir.join(&old_file_name);
+		let new_path = scratch_dir.join(&new_file_name);
+		std::fs::rename(&old_path, &new_path).map_err(|e| ErrorInner::DumpIo(old_path, e))?;
+
+		collections.push(CollectionManifestEntry {
+			database: entry.database,
+			collection: entry.collection,
+			file: new_file_name,
+			document_count: entry.count,
+		});
+	}
+
+	let upgraded = DumpMetadata {
+		dump_format: 2,
+		crate_version: old.crate_version,
+		mongodb_version: old.mongodb_version,
+		dumped_at: old.dumped_at,
+		collections,
+	};
+
+	let metadata_file =
+		File::create(&metadata_path).map_err(|e| ErrorInner::DumpIo(metadata_path.clone(), e))?;
+	serde_json::to_writer_pretty(metadata_file, &upgraded).map_err(ErrorInner::DumpMetadata)?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn upgrade_v1_rewrites_metadata_and_renames_collection_files() {
+		let scratch_dir = tempfile::tempdir().unwrap();
+
+		let old_metadata = serde_json::json!({
+			"crate_version": "0.1.0",
+			"mongodb_version": "7.0.0",
+			"dumped_at": 1_700_000_000u64,
+			"collections": [
+				{ "database": "test", "collection": "animals", "count": 2 },
+			],
+		});
+		std::fs::write(
+			scratch_dir.path().join(METADATA_FILE_NAME),
+			serde_json::to_vec(&old_metadata).unwrap(),
+		)
+		.unwrap();
+		std::fs::write(scratch_dir.path().join("test.animals.json"), "{}\n{}\n").unwrap();
+
+		upgrade(1, scratch_dir.path()).unwrap();
+
+		assert!(!scratch_dir.path().join("test.animals.json").exists());
+		assert!(scratch_dir.path().join("test__animals.ndjson").exists());
+
+		let upgraded: DumpMetadata =
+			serde_json::from_reader(File::open(scratch_dir.path().join(METADATA_FILE_NAME)).unwrap()).unwrap();
+		assert_eq!(upgraded.dump_format, 2);
+		assert_eq!(upgraded.collections.len(), 1);
+		assert_eq!(upgraded.collections[0].file, "test__animals.ndjson");
+		assert_eq!(upgraded.collections[0].document_count, 2);
+	}
+
+	#[test]
+	fn upgrade_rejects_unknown_format() {
+		let scratch_dir = tempfile::tempdir().unwrap();
+		let err = upgrade(99, scratch_dir.path()).unwrap_err();
+		assert!(format!("{err}").contains("99"));
+	}
+}
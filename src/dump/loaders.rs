@@ -0,0 +1,109 @@
+use crate::dump::{DumpMetadata, METADATA_FILE_NAME};
+use crate::error::ErrorInner;
+use crate::util::SeedData;
+use crate::Error;
+use mongodb::bson::Bson;
+use mongodb::Client;
+use std::fs::File;
+use std::future::Future;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::pin::Pin;
+
+/// Replays a dump archive (already unpacked into a scratch directory) into a [`Client`].
+///
+/// Only the current dump format has a `Loader` ([`V2Loader`]). Archives in an
+/// older format are first rewritten in place by the [`crate::dump::compat`]
+/// module into the current [`DumpMetadata`] shape and file layout, and then
+/// replayed by that same loader.
+pub trait Loader {
+	/// Replay every collection recorded in `scratch_dir`'s `metadata.json` into `client`.
+	fn load<'a>(
+		scratch_dir: &'a Path,
+		client: &'a Client,
+	) -> Pin<Box<dyn Future<Output = Result<(), Error>> + Send + 'a>>;
+}
+
+/// Loader for the current (`dump_format = 2`) archive layout.
+pub struct V2Loader;
+
+impl Loader for V2Loader {
+	fn load<'a>(
+		scratch_dir: &'a Path,
+		client: &'a Client,
+	) -> Pin<Box<dyn Future<Output = Result<(), Error>> + Send + 'a>> {
+		Box::pin(async move {
+			let metadata_path = scratch_dir.join(METADATA_FILE_NAME);
+			let metadata_file = File::open(&metadata_path)
+				.map_err(|e| ErrorInner::DumpIo(metadata_path.clone(), e))?;
+			let metadata: DumpMetadata =
+				serde_json::from_reader(metadata_file).map_err(ErrorInner::DumpMetadata)?;
+
+			for entry in &metadata.collections {
+				let file_path = scratch_dir.join(&entry.file);
+				let file =
+					File::open(&file_path).map_err(|e| ErrorInner::DumpIo(file_path.clone(), e))?;
+				let reader = BufReader::new(file);
+
+				let mut documents = Vec::new();
+				for line in reader.lines() {
+					let line = line.map_err(|e| ErrorInner::DumpIo(file_path.clone(), e))?;
+					if line.trim().is_empty() {
+						continue;
+					}
+					let value: serde_json::Value =
+						serde_json::from_str(&line).map_err(ErrorInner::DumpMetadata)?;
+					let bson = Bson::try_from(value).map_err(|e| ErrorInner::Dump(e.to_string()))?;
+					let document = bson.as_document().cloned().ok_or_else(|| {
+						ErrorInner::Dump(format!("non-document entry in {}", entry.file))
+					})?;
+					documents.push(document);
+				}
+
+				if documents.is_empty() {
+					continue;
+				}
+
+				SeedData::new()
+					.new_in(&entry.database, &entry.collection, documents)
+					.seed(client)
+					.await
+					.map_err(|e| ErrorInner::Dump(e.to_string()))?;
+			}
+
+			Ok(())
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use mongodb::bson::{doc, oid::ObjectId, Bson, DateTime};
+
+	/// Mirrors the two halves of [`super::V2Loader::load`]'s document handling:
+	/// the write side's `Bson::into_canonical_extjson()` (used by
+	/// `crate::dump::dump_collection_json`) and the read side's
+	/// `serde_json::Value` -> `Bson::try_from` parse of an ndjson line.
+	#[test]
+	fn extjson_round_trips_through_an_ndjson_line() {
+		let document = doc! {
+			"_id": ObjectId::new(),
+			"count": 7_i32,
+			"big_count": 7_000_000_000_i64,
+			"ratio": 1.5,
+			"name": "trex",
+			"active": true,
+			"created_at": DateTime::now(),
+			"tags": ["a", "b"],
+		};
+
+		let extjson = Bson::Document(document.clone()).into_canonical_extjson();
+		let line = serde_json::to_string(&extjson).unwrap();
+
+		let parsed: serde_json::Value = serde_json::from_str(&line).unwrap();
+		let bson = Bson::try_from(parsed).unwrap();
+		let round_tripped = bson.as_document().cloned().unwrap();
+
+		assert_eq!(round_tripped, document);
+	}
+}
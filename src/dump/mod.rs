@@ -0,0 +1,304 @@
+mod compat;
+mod loaders;
+
+use crate::error::ErrorInner;
+use crate::util::TempDir;
+use crate::Error;
+use futures_util::stream::TryStreamExt;
+use loaders::Loader;
+use mongodb::bson::{doc, Bson, Document};
+use mongodb::Client;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The name of the metadata file stored at the root of a dump archive.
+pub(crate) const METADATA_FILE_NAME: &str = "metadata.json";
+
+/// The dump format version produced by this version of the crate.
+///
+/// Bump this whenever [`DumpMetadata`]'s on-disk shape changes, and add a
+/// matching [`loaders::Loader`] (with a [`compat`] upgrade path from the
+/// previous version) so older archives keep restoring correctly.
+pub const CURRENT_DUMP_FORMAT: u32 = 2;
+
+/// Description of a single collection inside a dump archive.
+///
+/// This is part of [`DumpMetadata`] and lets [`restore()`] know which
+/// files to replay without having to guess database/collection names
+/// from file paths.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CollectionManifestEntry {
+	/// The database the collection belongs to.
+	pub database: String,
+	/// The name of the collection.
+	pub collection: String,
+	/// The file (relative to the archive root) holding the newline-delimited
+	/// extended-JSON documents for this collection.
+	pub file: String,
+	/// The number of documents that were dumped for this collection.
+	pub document_count: u64,
+}
+
+/// Metadata written to `metadata.json` at the root of every dump archive.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DumpMetadata {
+	/// The dump format version this metadata file was written in.
+	pub dump_format: u32,
+	/// The version of the `temp-mongo` crate that produced the dump.
+	pub crate_version: String,
+	/// The version reported by the `mongod` server that was dumped.
+	pub mongodb_version: String,
+	/// Unix timestamp (seconds) of when the dump was taken.
+	pub dumped_at: u64,
+	/// One entry per dumped collection.
+	pub collections: Vec<CollectionManifestEntry>,
+}
+
+/// Names of databases that are never dumped or restored.
+const SYSTEM_DATABASES: &[&str] = &["admin", "local", "config"];
+
+/// Snapshot every user database/collection of `client` into `out`, a `.tar.gz` archive.
+pub async fn dump(client: &Client, out: &Path) -> Result<(), Error> {
+	let scratch = TempDir::new(true).map_err(ErrorInner::MakeTempDir)?;
+
+	let mongodb_version = server_version(client).await?;
+	let mut collections = Vec::new();
+
+	for database_name in list_user_databases(client).await? {
+		let database = client.database(&database_name);
+		let collection_names = database
+			.list_collection_names(None)
+			.await
+			.map_err(|e| ErrorInner::Dump(e.to_string()))?;
+
+		for collection_name in collection_names {
+			let collection = database.collection::<Document>(&collection_name);
+			let file_name = format!("{database_name}__{collection_name}.ndjson");
+			let file_path = scratch.path().join(&file_name);
+			let mut file =
+				File::create(&file_path).map_err(|e| ErrorInner::DumpIo(file_path.clone(), e))?;
+
+			let mut cursor = collection
+				.find(None, None)
+				.await
+				.map_err(|e| ErrorInner::Dump(e.to_string()))?;
+
+			let mut document_count = 0u64;
+			while let Some(document) = cursor
+				.try_next()
+				.await
+				.map_err(|e| ErrorInner::Dump(e.to_string()))?
+			{
+				let extjson = Bson::Document(document).into_canonical_extjson();
+				writeln!(file, "{extjson}").map_err(|e| ErrorInner::DumpIo(file_path.clone(), e))?;
+				document_count += 1;
+			}
+
+			collections.push(CollectionManifestEntry {
+				database: database_name.clone(),
+				collection: collection_name,
+				file: file_name,
+				document_count,
+			});
+		}
+	}
+
+	let dumped_at = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|d| d.as_secs())
+		.unwrap_or(0);
+
+	let metadata = DumpMetadata {
+		dump_format: CURRENT_DUMP_FORMAT,
+		crate_version: env!("CARGO_PKG_VERSION").to_string(),
+		mongodb_version,
+		dumped_at,
+		collections,
+	};
+
+	let metadata_path = scratch.path().join(METADATA_FILE_NAME);
+	let metadata_file =
+		File::create(&metadata_path).map_err(|e| ErrorInner::DumpIo(metadata_path.clone(), e))?;
+	serde_json::to_writer_pretty(metadata_file, &metadata).map_err(ErrorInner::DumpMetadata)?;
+
+	write_archive(scratch.path(), out)?;
+
+	Ok(())
+}
+
+/// Unpack `archive`, detect its dump format and replay every collection into `client`.
+///
+/// The `dump_format` recorded in the archive's `metadata.json` decides
+/// whether [`compat`] first upgrades an older on-disk representation in
+/// place; the current [`loaders::Loader`] then replays the archive.
+/// Restoring an archive whose format is newer than
+/// [`CURRENT_DUMP_FORMAT`] fails with [`ErrorInner::UnsupportedDumpFormat`].
+pub async fn restore(client: &Client, archive: &Path) -> Result<(), Error> {
+	let scratch = TempDir::new(true).map_err(ErrorInner::MakeTempDir)?;
+	read_archive(archive, scratch.path())?;
+
+	let dump_format = read_dump_format(scratch.path())?;
+	if dump_format > CURRENT_DUMP_FORMAT {
+		return Err(ErrorInner::UnsupportedDumpFormat(dump_format).into());
+	}
+	if dump_format < CURRENT_DUMP_FORMAT {
+		compat::upgrade(dump_format, scratch.path())?;
+	}
+
+	loaders::V2Loader::load(scratch.path(), client).await
+}
+
+/// Read just the `dump_format` field out of `metadata.json`, without requiring
+/// the rest of the file to match the current [`DumpMetadata`] shape.
+fn read_dump_format(scratch_dir: &Path) -> Result<u32, Error> {
+	let metadata_path = scratch_dir.join(METADATA_FILE_NAME);
+	let metadata_file =
+		File::open(&metadata_path).map_err(|e| ErrorInner::DumpIo(metadata_path.clone(), e))?;
+	let value: serde_json::Value =
+		serde_json::from_reader(metadata_file).map_err(ErrorInner::DumpMetadata)?;
+	Ok(value
+		.get("dump_format")
+		.and_then(serde_json::Value::as_u64)
+		.unwrap_or(1) as u32)
+}
+
+/// List the names of every database that isn't a MongoDB system database.
+pub(crate) async fn list_user_databases(client: &Client) -> Result<Vec<String>, Error> {
+	let names = client
+		.list_database_names(None, None)
+		.await
+		.map_err(|e| ErrorInner::Dump(e.to_string()))?;
+	Ok(names
+		.into_iter()
+		.filter(|name| !SYSTEM_DATABASES.contains(&name.as_str()))
+		.collect())
+}
+
+/// Ask the server for its version string via the `buildInfo` command.
+async fn server_version(client: &Client) -> Result<String, Error> {
+	let reply = client
+		.database("admin")
+		.run_command(doc! { "buildInfo": 1 }, None)
+		.await
+		.map_err(|e| ErrorInner::Dump(e.to_string()))?;
+	Ok(reply.get_str("version").unwrap_or("unknown").to_string())
+}
+
+/// Compress `dir` into a gzipped tarball at `out`.
+fn write_archive(dir: &Path, out: &Path) -> Result<(), Error> {
+	let file = File::create(out).map_err(|e| ErrorInner::DumpIo(out.to_owned(), e))?;
+	let encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+	let mut builder = tar::Builder::new(encoder);
+	builder
+		.append_dir_all(".", dir)
+		.map_err(|e| ErrorInner::DumpIo(out.to_owned(), e))?;
+	builder
+		.into_inner()
+		.map_err(|e| ErrorInner::DumpIo(out.to_owned(), e))?;
+	Ok(())
+}
+
+/// Extract the gzipped tarball at `archive` into `dir`.
+fn read_archive(archive: &Path, dir: &Path) -> Result<(), Error> {
+	let file = File::open(archive).map_err(|e| ErrorInner::DumpIo(archive.to_owned(), e))?;
+	let decoder = flate2::read::GzDecoder::new(file);
+	let mut archive_reader = tar::Archive::new(decoder);
+	archive_reader
+		.unpack(dir)
+		.map_err(|e| ErrorInner::DumpIo(archive.to_owned(), e))?;
+	Ok(())
+}
+
+/// Insert every document of a plain JSON array file into a single collection.
+///
+/// Unlike [`dump`]/[`restore`], this reads a single JSON array file with no
+/// `metadata.json` manifest. Each element is converted with `Bson::try_from`,
+/// so extended-JSON wrappers such as `{"$oid": ...}` or `{"$date": ...}`
+/// become the matching BSON types, while plain JSON values keep their plain
+/// JSON types (e.g. a date written as a bare string stays a string).
+pub(crate) async fn load_json_file(
+	client: &Client,
+	database_name: &str,
+	collection_name: &str,
+	path: &Path,
+) -> Result<(), Error> {
+	let file = File::open(path).map_err(|e| ErrorInner::DumpIo(path.to_owned(), e))?;
+	let values: Vec<serde_json::Value> =
+		serde_json::from_reader(file).map_err(ErrorInner::DumpMetadata)?;
+
+	let documents = values
+		.into_iter()
+		.map(|value| match Bson::try_from(value) {
+			Ok(Bson::Document(document)) => Ok(document),
+			Ok(_) => Err(ErrorInner::Dump("JSON array element is not an object".to_string()).into()),
+			Err(e) => Err(ErrorInner::Dump(e.to_string()).into()),
+		})
+		.collect::<Result<Vec<Document>, Error>>()?;
+
+	if documents.is_empty() {
+		return Ok(());
+	}
+
+	client
+		.database(database_name)
+		.collection::<Document>(collection_name)
+		.insert_many(documents, None)
+		.await
+		.map_err(|e| ErrorInner::Dump(e.to_string()))?;
+	Ok(())
+}
+
+/// Write every document of a single collection to `out` as newline-delimited canonical extended-JSON.
+pub(crate) async fn dump_collection_json(
+	client: &Client,
+	database_name: &str,
+	collection_name: &str,
+	out: &Path,
+) -> Result<(), Error> {
+	let collection = client
+		.database(database_name)
+		.collection::<Document>(collection_name);
+
+	let mut file = File::create(out).map_err(|e| ErrorInner::DumpIo(out.to_owned(), e))?;
+	let mut cursor = collection
+		.find(None, None)
+		.await
+		.map_err(|e| ErrorInner::Dump(e.to_string()))?;
+	while let Some(document) = cursor
+		.try_next()
+		.await
+		.map_err(|e| ErrorInner::Dump(e.to_string()))?
+	{
+		let extjson = Bson::Document(document).into_canonical_extjson();
+		writeln!(file, "{extjson}").map_err(|e| ErrorInner::DumpIo(out.to_owned(), e))?;
+	}
+	Ok(())
+}
+
+/// Write every user database/collection to `dir` as one `<database>__<collection>.ndjson` file each.
+///
+/// Unlike [`dump`], this writes loose files into a plain directory instead of
+/// a `.tar.gz` archive, and without a `metadata.json` manifest, so the
+/// output is meant for eyeballing or golden-file comparisons rather than
+/// restoring with [`restore`].
+pub(crate) async fn dump_all(client: &Client, dir: &Path) -> Result<(), Error> {
+	std::fs::create_dir_all(dir).map_err(|e| ErrorInner::DumpIo(dir.to_owned(), e))?;
+
+	for database_name in list_user_databases(client).await? {
+		let database = client.database(&database_name);
+		let collection_names = database
+			.list_collection_names(None)
+			.await
+			.map_err(|e| ErrorInner::Dump(e.to_string()))?;
+
+		for collection_name in collection_names {
+			let file_name = format!("{database_name}__{collection_name}.ndjson");
+			let out = dir.join(file_name);
+			dump_collection_json(client, &database_name, &collection_name, &out).await?;
+		}
+	}
+
+	Ok(())
+}
@@ -11,6 +11,9 @@ pub enum ErrorInner {
 	/// Failed to create the temporary directory.
 	MakeTempDir(std::io::Error),
 
+	/// Failed to build the private tokio runtime backing [`crate::sync::TempMongo`].
+	MakeRuntime(std::io::Error),
+
 	/// Failed to create the database directory.
 	MakeDbDir(PathBuf, std::io::Error),
 
@@ -27,6 +30,36 @@ pub enum ErrorInner {
 	Connect(String, mongodb::error::Error),
 
 	Port,
+
+	/// Failed to read or write a file that is part of a dump archive.
+	DumpIo(PathBuf, std::io::Error),
+
+	/// Failed to serialize or deserialize dump archive metadata.
+	DumpMetadata(serde_json::Error),
+
+	/// A MongoDB operation failed while dumping or restoring an archive.
+	Dump(String),
+
+	/// The archive was produced by a dump format newer than this crate supports.
+	UnsupportedDumpFormat(u32),
+
+	/// Failed to load or seed fixture data.
+	Seed(String),
+
+	/// A freshly initiated replica set did not elect a primary in time.
+	ReplicaSetTimeout(String),
+
+	/// `mongod` exited, or did not become ready to accept connections before the startup deadline.
+	StartupTimeout(String),
+
+	/// An operation run through [`crate::TempMongo::run_with_timeout`] did not finish in time.
+	TimedOut(String),
+
+	/// Failed to run or parse the result of the `validate` command.
+	Validate(String),
+
+	/// Failed to snapshot or clear a cached `mongod` data-directory.
+	Cache(String),
 }
 
 impl std::error::Error for Error {}
@@ -47,6 +80,7 @@ impl std::fmt::Display for ErrorInner {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
 			Self::MakeTempDir(e) => write!(f, "Failed to create temporary directory: {e}"),
+			Self::MakeRuntime(e) => write!(f, "Failed to build tokio runtime: {e}"),
 			Self::MakeDbDir(path, e) => {
 				write!(f, "Failed to create data directory {}: {e}", path.display())
 			}
@@ -59,6 +93,27 @@ impl std::fmt::Display for ErrorInner {
 			),
 			Self::Connect(address, e) => write!(f, "Failed to connect to server at {address}: {e}"),
 			Self::Port => write!(f, "Failed to select a free port by the os "),
+			Self::DumpIo(path, e) => {
+				write!(f, "Failed to access dump file {}: {e}", path.display())
+			}
+			Self::DumpMetadata(e) => write!(f, "Failed to read dump archive metadata: {e}"),
+			Self::Dump(message) => write!(f, "Failed to dump or restore archive: {message}"),
+			Self::UnsupportedDumpFormat(version) => write!(
+				f,
+				"Dump archive uses format version {version}, which is newer than this crate supports"
+			),
+			Self::Seed(message) => write!(f, "Failed to seed fixture data: {message}"),
+			Self::ReplicaSetTimeout(name) => write!(
+				f,
+				"Replica set '{name}' did not elect a primary before the startup deadline"
+			),
+			Self::StartupTimeout(log_tail) => write!(
+				f,
+				"mongod exited or did not become ready before the startup deadline; tail of mongod.log:\n{log_tail}"
+			),
+			Self::TimedOut(message) => write!(f, "Operation timed out: {message}"),
+			Self::Validate(message) => write!(f, "Failed to validate collection: {message}"),
+			Self::Cache(message) => write!(f, "Failed to snapshot or clear cached data directory: {message}"),
 		}
 	}
 }
@@ -29,11 +29,21 @@
 
 #![warn(missing_docs)]
 
+mod cache;
+mod dump;
 mod error;
 mod temp_mongo;
 mod util;
 
+/// Blocking mirror of [`TempMongo`], for test suites with no async runtime.
+#[cfg(feature = "sync")]
+pub mod sync;
+
+pub use cache::digest as cache_digest;
+pub use dump::{CollectionManifestEntry, DumpMetadata};
 pub use error::Error;
+pub use temp_mongo::AuthCredentials;
 pub use temp_mongo::TempMongo;
 pub use temp_mongo::TempMongoBuilder;
-pub use util::DataSeeder;
+pub use temp_mongo::ValidationReport;
+pub use util::{ColumnType, CsvOptions, SeedData, ShutdownMode};
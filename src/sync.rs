@@ -0,0 +1,102 @@
+//! Blocking mirror of [`crate::TempMongo`], for test suites with no async runtime.
+//!
+//! Enabled by the `sync` feature. Every method here drives the async
+//! implementation on a private current-thread tokio runtime owned by the
+//! struct, so callers never need `#[tokio::main]` or `.await`.
+
+use crate::error::ErrorInner;
+use crate::util::SeedData;
+use crate::Error;
+use mongodb::bson::Document;
+use std::path::Path;
+use tokio::runtime::{Builder, Runtime};
+
+/// A blocking mirror of [`crate::TempMongo`].
+pub struct TempMongo {
+	runtime: Runtime,
+	inner: crate::TempMongo,
+}
+
+impl TempMongo {
+	/// Spawn a new MongoDB instance with default port configuration.
+	pub fn new() -> Result<Self, Error> {
+		TempMongoBuilder::new().spawn()
+	}
+
+	/// Create a builder to customize your [`TempMongo`].
+	///
+	/// After configuring the desired options, run [`TempMongoBuilder::spawn()`].
+	pub fn builder() -> TempMongoBuilder {
+		TempMongoBuilder::new()
+	}
+
+	/// Get the path of the temporary state directory.
+	pub fn directory(&self) -> &Path {
+		self.inner.directory()
+	}
+
+	/// Prepare seed document row with &str for db name and collection name into mongoDB database instance
+	pub fn prepare_seed_document(
+		&self,
+		database_name: &str,
+		collection_name: &str,
+		documents: Vec<Document>,
+	) -> SeedData {
+		self.inner
+			.prepare_seed_document(database_name, collection_name, documents)
+	}
+
+	/// Seed document into MongoDB database.
+	/// # Arguments
+	/// * `seed_data` - The seed data to insert into the database
+	pub fn load_document(&self, seed_data: &SeedData) -> mongodb::error::Result<()> {
+		self.runtime.block_on(self.inner.load_document(seed_data))
+	}
+
+	/// Advanced printing of documents in a collection.
+	/// # Arguments
+	/// * `db_name` - The name of the database
+	/// * `collection_name` - The name of the collection
+	pub fn print_documents(&self, db_name: &str, collection_name: &str) -> mongodb::error::Result<()> {
+		self.runtime
+			.block_on(self.inner.print_documents(db_name, collection_name))
+	}
+
+	/// Kill the server and remove the temporary state directory on the filesystem.
+	pub fn kill_and_clean(self) -> Result<(), Error> {
+		let Self { runtime, inner } = self;
+		runtime.block_on(inner.kill_and_clean())
+	}
+}
+
+/// Blocking mirror of [`crate::TempMongoBuilder`].
+///
+/// After configuring the desired options, run [`TempMongoBuilder::spawn()`].
+pub struct TempMongoBuilder {
+	inner: crate::TempMongoBuilder,
+}
+
+impl TempMongoBuilder {
+	/// Create a new builder.
+	pub fn new() -> Self {
+		Self {
+			inner: crate::TempMongoBuilder::new(),
+		}
+	}
+
+	/// Spawn the MongoDB server and connect to it, blocking the current thread.
+	pub fn spawn(self) -> Result<TempMongo, Error> {
+		let runtime = Builder::new_current_thread()
+			.enable_all()
+			.build()
+			.map_err(ErrorInner::MakeRuntime)?;
+		let inner = runtime.block_on(self.inner.spawn())?;
+		Ok(TempMongo { runtime, inner })
+	}
+}
+
+impl Default for TempMongoBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
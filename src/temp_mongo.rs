@@ -1,14 +1,70 @@
 use crate::error::ErrorInner;
-use crate::util::{DataSeeder, KillOnDrop, PortGenerator, TempDir};
+use crate::util::runtime::{sleep, spawn_blocking};
+use crate::util::{KillOnDrop, PortGenerator, SeedData, ShutdownMode, TempDir};
 use crate::Error;
 use futures_util::stream::TryStreamExt;
-use mongodb::bson::Document;
-use mongodb::options::{ClientOptions, ServerAddress};
+use mongodb::bson::{doc, Document};
+use mongodb::options::{ClientOptions, Credential, ServerAddress};
 use std::ffi::{OsStr, OsString};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::time::Duration;
-use tokio::time::sleep;
+
+/// The root user credentials of a [`TempMongo`] started with [`TempMongoBuilder::with_auth`].
+#[derive(Debug, Clone)]
+pub struct AuthCredentials {
+	/// The username of the root user.
+	pub username: String,
+	/// The password of the root user.
+	pub password: String,
+	/// The database the user was created in, and authenticates against.
+	pub auth_source: String,
+}
+
+/// The result of running MongoDB's `validate` command on a collection, via [`TempMongo::validate`].
+///
+/// Covers the reply shape of the server versions this crate is tested
+/// against (6.0 through 7.0). Older servers report `warnings`/`errors` as a
+/// single concatenated string rather than an array; [`string_or_vec`]
+/// accepts either. `invalid_document_count` comes through as BSON `Int32` on
+/// some server versions and `Int64` on others; serde's numeric coercion
+/// already widens either into this field's `i64`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ValidationReport {
+	/// Whether the collection passed validation.
+	pub valid: bool,
+	/// The namespace (`<database>.<collection>`) that was validated.
+	pub ns: String,
+	/// Non-fatal issues the validation pass noticed.
+	#[serde(default, deserialize_with = "string_or_vec")]
+	pub warnings: Vec<String>,
+	/// Fatal structural errors found in the collection.
+	#[serde(default, deserialize_with = "string_or_vec")]
+	pub errors: Vec<String>,
+	/// Number of documents found to be invalid.
+	#[serde(default, rename = "nInvalidDocuments")]
+	pub invalid_document_count: i64,
+}
+
+/// Deserialize a field that the `validate` command reports as either a bare
+/// string or an array of strings, depending on server version, into a `Vec<String>`.
+fn string_or_vec<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+	D: serde::Deserializer<'de>,
+{
+	#[derive(serde::Deserialize)]
+	#[serde(untagged)]
+	enum StringOrVec {
+		String(String),
+		Vec(Vec<String>),
+	}
+
+	Ok(match StringOrVec::deserialize(deserializer)? {
+		StringOrVec::String(s) if s.is_empty() => Vec::new(),
+		StringOrVec::String(s) => vec![s],
+		StringOrVec::Vec(v) => v,
+	})
+}
 
 /// A temporary MongoDB instance.
 ///
@@ -20,7 +76,9 @@ pub struct TempMongo {
 	log_path: PathBuf,
 	client: mongodb::Client,
 	server: KillOnDrop,
-	seed: DataSeeder,
+	seed: SeedData,
+	credentials: Option<AuthCredentials>,
+	shutdown_timeout: Duration,
 }
 
 impl std::fmt::Debug for TempMongo {
@@ -37,7 +95,7 @@ impl std::fmt::Debug for TempMongo {
 impl TempMongo {
 	/// Spawn a new MongoDB instance with default port configuration.
 	pub async fn new() -> Result<Self, Error> {
-		Self::from_builder(&TempMongoBuilder::new()).await
+		Self::from_builder(&TempMongoBuilder::new(), None).await
 	}
 
 	/// Create a builder to customize your [`TempMongo`].
@@ -73,7 +131,7 @@ impl TempMongo {
 		database_name: &str,
 		collection_name: &str,
 		documents: Vec<Document>,
-	) -> DataSeeder {
+	) -> SeedData {
 		self.seed.new_in(database_name, collection_name, documents)
 	}
 
@@ -83,16 +141,90 @@ impl TempMongo {
 		database_name: &String,
 		collection_name: &String,
 		documents: Vec<Document>,
-	) -> DataSeeder {
+	) -> SeedData {
 		self.seed
-			.new_in_with_string(database_name, collection_name, documents)
+			.new_with_string(database_name, collection_name, documents)
 	}
 
 	/// Seed document into MongoDB database
 	/// # Arguments
 	/// * `seed_data` - The seed data to insert into the database
-	pub async fn load_document(&self, seed_data: &DataSeeder) -> mongodb::error::Result<()> {
-		seed_data.seed_document(&self.client).await
+	pub async fn load_document(&self, seed_data: &SeedData) -> mongodb::error::Result<()> {
+		seed_data.seed(&self.client).await
+	}
+
+	/// Serialize and insert typed domain structs/enums directly into a collection.
+	///
+	/// Each item is serialized to a `Document` via [`mongodb::bson::to_document`],
+	/// so fixtures can be ordinary `Serialize` structs instead of hand-built
+	/// `bson::doc!` values. See [`Self::fetch_all`]/[`Self::fetch_one`] for the
+	/// typed read side.
+	///
+	/// # Errors
+	/// Returns an error if any item fails to serialize, or if the MongoDB
+	/// insert fails.
+	pub async fn seed_typed<T: serde::Serialize>(
+		&self,
+		database_name: &str,
+		collection_name: &str,
+		items: &[T],
+	) -> Result<(), Error> {
+		self.load_typed(database_name, collection_name, items).await
+	}
+
+	/// Fetch every document in a collection, deserialized into `T`.
+	///
+	/// # Errors
+	/// Returns an error if the query fails, or if any document fails to
+	/// deserialize into `T`.
+	pub async fn fetch_all<T>(&self, database_name: &str, collection_name: &str) -> mongodb::error::Result<Vec<T>>
+	where
+		T: serde::de::DeserializeOwned + Send + Sync,
+	{
+		let collection = self
+			.client
+			.database(database_name)
+			.collection::<T>(collection_name);
+		collection.find(None, None).await?.try_collect().await
+	}
+
+	/// Fetch a single document matching `filter`, deserialized into `T`.
+	///
+	/// # Errors
+	/// Returns an error if the query fails, or if the matched document fails
+	/// to deserialize into `T`.
+	pub async fn fetch_one<T>(
+		&self,
+		database_name: &str,
+		collection_name: &str,
+		filter: Document,
+	) -> mongodb::error::Result<Option<T>>
+	where
+		T: serde::de::DeserializeOwned + Send + Sync,
+	{
+		let collection = self
+			.client
+			.database(database_name)
+			.collection::<T>(collection_name);
+		collection.find_one(filter, None).await
+	}
+
+	/// Seed many independent collections concurrently from pre-built [`SeedData`]s.
+	///
+	/// Each seeder drives its own collection via [`SeedData::seed`], and all of
+	/// them run concurrently rather than one after another. See
+	/// [`SeedData::from_json_file`] and [`SeedData::from_directory`] for
+	/// building seeders from fixture files.
+	///
+	/// # Errors
+	/// Returns an error if any MongoDB operation fails while seeding.
+	pub async fn seed_all(&self, seeds: &[SeedData]) -> mongodb::error::Result<()> {
+		SeedData::load_many(seeds, &self.client).await
+	}
+
+	/// Get the root user credentials, if this instance was started with [`TempMongoBuilder::with_auth`].
+	pub fn credentials(&self) -> Option<&AuthCredentials> {
+		self.credentials.as_ref()
 	}
 
 	/// Get a client for the MongDB instance.
@@ -127,6 +259,48 @@ impl TempMongo {
 		Ok(())
 	}
 
+	/// Shut down the server gracefully, then remove the temporary state directory.
+	///
+	/// Runs the `shutdown` admin command, which asks `mongod` to flush and
+	/// close cleanly instead of being killed outright, and waits up to
+	/// [`TempMongoBuilder::shutdown_timeout`] for the process to exit on its
+	/// own. If it hasn't exited by then, falls back to [`Self::kill_and_clean`]'s
+	/// `SIGKILL` behavior so this never hangs indefinitely.
+	///
+	/// # Errors
+	/// Returns an error if the server cannot be killed (in the fallback path)
+	/// or if the temporary state directory cannot be removed.
+	pub async fn shutdown_gracefully(mut self) -> Result<(), Error> {
+		// The connection drops as part of the server shutting down, so a
+		// transport-level error here is expected and not a failure.
+		let _ = self
+			.client
+			.database("admin")
+			.run_command(doc! { "shutdown": 1 }, None)
+			.await;
+		// Belt-and-suspenders: also send SIGTERM, in case the shutdown command
+		// didn't reach the server (e.g. it had already dropped the connection).
+		self.server.terminate().map_err(ErrorInner::KillServer)?;
+
+		let deadline = std::time::Instant::now() + self.shutdown_timeout;
+		loop {
+			if matches!(self.server.try_wait(), Ok(Some(_))) {
+				break;
+			}
+			if std::time::Instant::now() >= deadline {
+				self.server.kill().map_err(ErrorInner::KillServer)?;
+				break;
+			}
+			sleep(STARTUP_RETRY_BACKOFF).await;
+		}
+
+		let path = self.tempdir.path().to_owned();
+		self.tempdir
+			.close()
+			.map_err(|e| ErrorInner::CleanDir(path, e))?;
+		Ok(())
+	}
+
 	/// Kill the server, but leave the temporary state directory on the filesystem.
 	///
 	/// Note that the server will also be killed when this object is dropped.
@@ -140,6 +314,247 @@ impl TempMongo {
 		Ok(())
 	}
 
+	/// Load every fixture file found under a directory tree into this instance.
+	///
+	/// See [`crate::util::SeedData::from_directory`] for how database and
+	/// collection names are derived from the directory layout, and how each
+	/// file's format is auto-detected.
+	///
+	/// # Errors
+	/// Returns an error if a fixture file cannot be read or parsed, or if any
+	/// MongoDB operation fails while seeding.
+	pub async fn load_directory(&self, root: &Path) -> Result<(), Error> {
+		let seeds = crate::util::SeedData::new()
+			.from_directory(root)
+			.map_err(|e| ErrorInner::Seed(e.to_string()))?;
+		crate::util::SeedData::load_many(&seeds, &self.client)
+			.await
+			.map_err(|e| ErrorInner::Seed(e.to_string()))?;
+		Ok(())
+	}
+
+	/// Seed a collection directly from typed domain structs/enums.
+	///
+	/// Each item is serialized to a `Document` via [`mongodb::bson::to_document`]
+	/// (see [`crate::util::SeedData::from_typed`]), so fixtures can be ordinary
+	/// `Serialize` structs instead of hand-built `bson::doc!` values.
+	///
+	/// # Errors
+	/// Returns an error if any item fails to serialize, or if the MongoDB
+	/// insert fails.
+	pub async fn load_typed<T: serde::Serialize>(
+		&self,
+		database_name: &str,
+		collection_name: &str,
+		items: &[T],
+	) -> Result<(), Error> {
+		let seed = crate::util::SeedData::new()
+			.from_typed(database_name, collection_name, items)
+			.map_err(|e| ErrorInner::Seed(e.to_string()))?;
+		seed.seed(&self.client)
+			.await
+			.map_err(|e| ErrorInner::Seed(e.to_string()))?;
+		Ok(())
+	}
+
+	/// Run the `validate` command against a collection and parse its report.
+	///
+	/// Set `full` to run a more thorough (and slower) pass; see the MongoDB
+	/// manual for the `validate` command's `full` option.
+	///
+	/// # Errors
+	/// Returns an error if the command fails, or if its reply doesn't match [`ValidationReport`].
+	pub async fn validate(
+		&self,
+		database_name: &str,
+		collection_name: &str,
+		full: bool,
+	) -> Result<ValidationReport, Error> {
+		let reply = self
+			.client
+			.database(database_name)
+			.run_command(doc! { "validate": collection_name, "full": full }, None)
+			.await
+			.map_err(|e| ErrorInner::Validate(e.to_string()))?;
+		mongodb::bson::from_document(reply).map_err(|e| ErrorInner::Validate(e.to_string()).into())
+	}
+
+	/// Run `op` to completion on its own task, timing out on the join handle instead of on `op` itself.
+	///
+	/// The driver documents that dropping one of its futures mid-poll (which
+	/// is exactly what wrapping it directly in `tokio::time::timeout` does)
+	/// can corrupt its internal state, since a cancelled operation may have
+	/// sent part of a request to the server. `tokio::spawn`ing `op` guarantees
+	/// it keeps being polled to completion in the background even if this
+	/// call times out and returns first.
+	///
+	/// # Errors
+	/// Returns an error if `op` doesn't finish within `timeout`, or if its task panics.
+	pub async fn run_with_timeout<T>(
+		&self,
+		timeout: Duration,
+		op: impl std::future::Future<Output = T> + Send + 'static,
+	) -> Result<T, Error>
+	where
+		T: Send + 'static,
+	{
+		let handle = tokio::spawn(op);
+		match tokio::time::timeout(timeout, handle).await {
+			Ok(Ok(value)) => Ok(value),
+			Ok(Err(join_error)) => Err(ErrorInner::TimedOut(format!("task panicked: {join_error}")).into()),
+			Err(_) => Err(ErrorInner::TimedOut(format!("operation did not complete within {timeout:?}")).into()),
+		}
+	}
+
+	/// Drop every user database, leaving a clean slate for reuse across tests.
+	///
+	/// Enumerates databases via `list_database_names`, skipping `admin`,
+	/// `config` and `local`, and drops each remaining one outright, indexes
+	/// included. Seeding again recreates the collections but no indexes
+	/// other than the default `_id` one.
+	///
+	/// # Errors
+	/// Returns an error if the database list cannot be read, or if any
+	/// database fails to drop.
+	pub async fn reset(&self) -> Result<(), Error> {
+		for database_name in crate::dump::list_user_databases(&self.client).await? {
+			self.client
+				.database(&database_name)
+				.drop(None)
+				.await
+				.map_err(|e| ErrorInner::Seed(e.to_string()))?;
+		}
+		Ok(())
+	}
+
+	/// Parse a plain JSON array file and insert every element as a document.
+	///
+	/// Unlike [`Self::dump`]/[`Self::restore`], this reads a single JSON array
+	/// file rather than an archive. Extended-JSON wrappers such as
+	/// `{"$oid": ...}` become the matching BSON types; plain JSON values keep
+	/// their plain JSON types.
+	///
+	/// # Errors
+	/// Returns an error if the file cannot be read or parsed, or if any
+	/// element isn't a JSON object, or if the MongoDB insert fails.
+	pub async fn load_json_file(&self, database_name: &str, collection_name: &str, path: &Path) -> Result<(), Error> {
+		crate::dump::load_json_file(&self.client, database_name, collection_name, path).await
+	}
+
+	/// Write every document of a collection to `out` as newline-delimited canonical extended-JSON.
+	///
+	/// # Errors
+	/// Returns an error if the MongoDB query fails, or the file cannot be written.
+	pub async fn dump_collection_json(
+		&self,
+		database_name: &str,
+		collection_name: &str,
+		out: &Path,
+	) -> Result<(), Error> {
+		crate::dump::dump_collection_json(&self.client, database_name, collection_name, out).await
+	}
+
+	/// Write every user database/collection to `dir`, one `<database>__<collection>.ndjson` file each.
+	///
+	/// Unlike [`Self::dump`], this writes loose files into a plain directory
+	/// rather than a single `.tar.gz` archive, for golden-file comparisons.
+	///
+	/// # Errors
+	/// Returns an error if any MongoDB operation fails, or a file cannot be written.
+	pub async fn dump_all(&self, dir: &Path) -> Result<(), Error> {
+		crate::dump::dump_all(&self.client, dir).await
+	}
+
+	/// Snapshot every user database/collection into a `.tar.gz` archive at `out`.
+	///
+	/// Each collection is stored as newline-delimited MongoDB Extended JSON
+	/// (canonical mode), which preserves BSON types like `ObjectId`, `Date`
+	/// and `Int32` across the archive boundary. A `metadata.json` at the
+	/// archive root records the crate version, the `mongod` server version,
+	/// the dump timestamp and a manifest of every dumped collection.
+	///
+	/// # Errors
+	/// Returns an error if any MongoDB operation fails, or if the archive
+	/// cannot be written to `out`.
+	pub async fn dump(&self, out: &Path) -> Result<(), Error> {
+		crate::dump::dump(&self.client, out).await
+	}
+
+	/// Restore a `.tar.gz` archive previously written by [`Self::dump()`].
+	///
+	/// Every collection recorded in the archive's `metadata.json` is
+	/// replayed into this instance through the existing seeding path.
+	///
+	/// # Errors
+	/// Returns an error if the archive cannot be read, or if any MongoDB
+	/// operation fails while replaying its documents.
+	pub async fn restore(&self, archive: &Path) -> Result<(), Error> {
+		crate::dump::restore(&self.client, archive).await
+	}
+
+	/// Spawn an instance, reusing a cached `mongod` data-directory snapshot for `key` if one exists.
+	///
+	/// On a cache hit, the snapshot previously saved by [`Self::persist_to_cache`]
+	/// is copied into a fresh `TempDir` and `mongod` is started directly over
+	/// it, skipping seeding entirely. On a miss, this behaves like [`Self::new()`];
+	/// seed the returned instance and call [`Self::persist_to_cache`] afterwards
+	/// so the next call with the same `key` can reuse it. Key cached snapshots
+	/// by a digest of the seed inputs (see [`crate::cache_digest`]) so a
+	/// changed fixture naturally misses the stale cache entry.
+	///
+	/// # Errors
+	/// Returns an error if the cached snapshot cannot be copied, or if
+	/// spawning `mongod` fails.
+	pub async fn from_cache(key: &str) -> Result<Self, Error> {
+		Self::from_builder(&TempMongoBuilder::new(), Some(key)).await
+	}
+
+	/// Persist this instance's `mongod` data directory as the cached snapshot for `key`.
+	///
+	/// `mongod` keeps running and writing throughout, so the data directory is
+	/// first frozen with the `fsyncLock` command (which flushes and blocks
+	/// further writes) and unlocked again once the copy is done. Without this,
+	/// the copy could race `mongod`'s own writes and save a torn, unrestorable
+	/// snapshot.
+	///
+	/// # Errors
+	/// Returns an error if `fsyncLock` fails, or if the data directory cannot be copied.
+	pub async fn persist_to_cache(&self, key: &str) -> Result<(), Error> {
+		self.client
+			.database("admin")
+			.run_command(doc! { "fsyncLock": 1 }, None)
+			.await
+			.map_err(|e| ErrorInner::Cache(e.to_string()))?;
+
+		let result = crate::cache::store(key, &self.tempdir.path().join("db"));
+
+		// Always try to unlock, even if the copy failed, so the instance
+		// isn't left stuck refusing writes.
+		let _ = self
+			.client
+			.database("admin")
+			.run_command(doc! { "fsyncUnlock": 1 }, None)
+			.await;
+
+		result
+	}
+
+	/// Remove the cached data-directory snapshot for `key`, if any.
+	///
+	/// # Errors
+	/// Returns an error if the cached snapshot exists but cannot be removed.
+	pub fn clear_cache(key: &str) -> Result<(), Error> {
+		crate::cache::clear(key)
+	}
+
+	/// Remove every cached data-directory snapshot, for every key.
+	///
+	/// # Errors
+	/// Returns an error if the cache root exists but cannot be removed.
+	pub fn clear_all_cache() -> Result<(), Error> {
+		crate::cache::clear_all()
+	}
+
 	/// Advanced printing of documents in a collection
 	/// # Arguments
 	/// * `db_name` - The name of the database
@@ -179,13 +594,26 @@ impl TempMongo {
 	/// # Errors
 	/// This function can return errors related to creating temporary directories, starting the MongoDB
 	/// server, and configuring the MongoDB client.
-	async fn from_builder(builder: &TempMongoBuilder) -> Result<Self, Error> {
+	async fn from_builder(builder: &TempMongoBuilder, cache_key: Option<&str>) -> Result<Self, Error> {
 		let tempdir = builder.make_temp_dir().map_err(ErrorInner::MakeTempDir)?;
 		let db_dir = tempdir.path().join("db");
 		let log_path = tempdir.path().join("mongod.log");
-		let seed = DataSeeder::new();
+		let seed = SeedData::new();
 
-		std::fs::create_dir(&db_dir).map_err(|e| ErrorInner::MakeDbDir(db_dir.clone(), e))?;
+		// Restoring a snapshot copies an arbitrarily large data directory, so
+		// run it on a blocking-safe thread rather than tying up the async
+		// task that's driving `mongod`'s own startup.
+		let restored_from_cache = match cache_key {
+			Some(key) => {
+				let key = key.to_string();
+				let db_dir = db_dir.clone();
+				spawn_blocking(move || crate::cache::restore_into(&key, &db_dir)).await?
+			}
+			None => false,
+		};
+		if !restored_from_cache {
+			std::fs::create_dir(&db_dir).map_err(|e| ErrorInner::MakeDbDir(db_dir.clone(), e))?;
+		}
 
 		let server_address: String;
 		let socket_path: PathBuf;
@@ -213,22 +641,28 @@ impl TempMongo {
 
 		//TODO: Add some error handling when spawning the service
 		//We might need to hide away the spawning of the server in a new class
-		let server = Command::new(builder.get_command())
+		let mut command = Command::new(builder.get_command());
+		command
 			.arg("--bind_ip")
 			.arg(&server_address)
 			.arg("--dbpath")
 			.arg(&db_dir)
 			.arg("--logpath")
 			.arg(&log_path)
-			.arg("--noauth")
+			.arg(if builder.auth.is_some() { "--auth" } else { "--noauth" })
 			.arg("--port")
 			.arg(mongodb_port.to_string())
 			.stdout(Stdio::piped())
-			.stderr(Stdio::piped())
+			.stderr(Stdio::piped());
+		if let Some(replica_set) = &builder.replica_set {
+			command.arg("--replSet").arg(replica_set);
+		}
+		let server = command
 			.spawn()
 			.map_err(|e| ErrorInner::SpawnServer(builder.get_command_string(), e))?;
 
-		let server = KillOnDrop::new(server);
+		let mut server = KillOnDrop::new(server);
+		server.set_shutdown_mode(builder.drop_shutdown_mode);
 
 		let mut hosts = Vec::new();
 
@@ -254,19 +688,86 @@ impl TempMongo {
 			});
 		}
 
-		let client_options = ClientOptions::builder()
+		let mut client_options = ClientOptions::builder()
 			.hosts(hosts)
 			.connect_timeout(Duration::from_millis(100))
 			.direct_connection(true)
 			.build();
 
-		let client = mongodb::Client::with_options(client_options.clone())
+		let mut client = mongodb::Client::with_options(client_options.clone())
 			.map_err(|e| ErrorInner::Connect(server_address.clone(), e))?;
 
-		client
-			.list_databases(None, None)
-			.await
-			.map_err(|e| ErrorInner::Connect(server_address, e))?;
+		wait_until_ready(&client, &mut server, builder.startup_timeout, &log_path).await?;
+
+		if let Some(replica_set) = &builder.replica_set {
+			// `replSetInitiate`'s member `host` is matched against the
+			// addresses the server itself is bound to, to identify "this
+			// node" in the new config. On Unix, `server_address` is the
+			// `mongod.sock` filesystem path used for the client connection,
+			// which isn't a valid match for that purpose; `mongod` also
+			// listens on the loopback TCP port regardless of platform, so use
+			// that instead.
+			let replica_set_host = format!("127.0.0.1:{mongodb_port}");
+			initiate_replica_set(&client, replica_set, &replica_set_host).await?;
+
+			// Once the replica set has a primary, reconnect with the driver's
+			// replica-set topology discovery enabled (instead of the raw
+			// direct connection used above just to reach `mongod` at all),
+			// pointed at it via `?replicaSet=<name>`. This is what unlocks
+			// `client.start_session()` and `Collection::watch()`.
+			client_options = ClientOptions::builder()
+				.hosts(client_options.hosts.clone())
+				.connect_timeout(Duration::from_millis(100))
+				.repl_set_name(replica_set.clone())
+				.build();
+			client = mongodb::Client::with_options(client_options.clone())
+				.map_err(|e| ErrorInner::Connect(server_address.clone(), e))?;
+		}
+
+		let (client, credentials) = match &builder.auth {
+			Some(auth) => {
+				// No users exist yet, so the "localhost exception" lets us
+				// create the root user over this still-unauthenticated client.
+				client
+					.database(&auth.auth_source)
+					.run_command(
+						doc! {
+							"createUser": &auth.username,
+							"pwd": &auth.password,
+							// The built-in `root` role only exists in `admin`,
+							// regardless of which database the user itself is
+							// created in (`auth.auth_source`).
+							"roles": [{ "role": "root", "db": "admin" }],
+						},
+						None,
+					)
+					.await
+					.map_err(|e| ErrorInner::Connect(server_address.clone(), e))?;
+
+				let credential = Credential::builder()
+					.username(auth.username.clone())
+					.password(auth.password.clone())
+					.source(auth.auth_source.clone())
+					.build();
+				let mut authenticated_builder = ClientOptions::builder()
+					.hosts(client_options.hosts.clone())
+					.connect_timeout(Duration::from_millis(100))
+					.credential(credential);
+				authenticated_builder = match &client_options.repl_set_name {
+					Some(name) => authenticated_builder.repl_set_name(name.clone()),
+					None => authenticated_builder.direct_connection(true),
+				};
+				let authenticated_client = mongodb::Client::with_options(authenticated_builder.build())
+					.map_err(|e| ErrorInner::Connect(server_address.clone(), e))?;
+				authenticated_client
+					.list_databases(None, None)
+					.await
+					.map_err(|e| ErrorInner::Connect(server_address, e))?;
+
+				(authenticated_client, Some(auth.clone()))
+			}
+			None => (client, None),
+		};
 
 		Ok(Self {
 			tempdir,
@@ -275,9 +776,121 @@ impl TempMongo {
 			server,
 			client,
 			seed,
+			credentials,
+			shutdown_timeout: builder.shutdown_timeout,
 		})
 	}
 }
+
+/// Default overall deadline for `mongod` to start accepting connections.
+const DEFAULT_STARTUP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Backoff between connection attempts while waiting for `mongod` to become ready.
+const STARTUP_RETRY_BACKOFF: Duration = Duration::from_millis(75);
+
+/// Number of trailing bytes of `mongod.log` to include in a startup timeout error.
+const LOG_TAIL_BYTES: usize = 4096;
+
+/// Poll `client` until it responds to `hello`, `server` exits early, or `timeout` elapses.
+///
+/// A single connection attempt right after spawning `mongod` is flaky on
+/// slower machines or cold filesystem caches, since the server hasn't
+/// necessarily opened its socket yet. This retries on a fixed backoff
+/// instead, while also checking `server` hasn't already exited so a crashed
+/// `mongod` aborts immediately rather than spinning until the deadline.
+///
+/// Probing with `hello` (rather than e.g. `list_databases`) matters once
+/// `--auth` is enabled: before any user exists, the "localhost exception"
+/// only permits `createUser`, so an unauthenticated `listDatabases` would
+/// fail with `Unauthorized` for the entire startup window and this would
+/// spin until `timeout` even though `mongod` is actually up. `hello` is
+/// exempt from authentication and always succeeds once the server is ready.
+async fn wait_until_ready(
+	client: &mongodb::Client,
+	server: &mut KillOnDrop,
+	timeout: Duration,
+	log_path: &Path,
+) -> Result<(), Error> {
+	let deadline = std::time::Instant::now() + timeout;
+
+	loop {
+		let ready = client
+			.database("admin")
+			.run_command(doc! { "hello": 1 }, None)
+			.await
+			.is_ok();
+		if ready {
+			return Ok(());
+		}
+
+		if matches!(server.try_wait(), Ok(Some(_))) {
+			return Err(ErrorInner::StartupTimeout(read_log_tail(log_path)).into());
+		}
+
+		if std::time::Instant::now() >= deadline {
+			return Err(ErrorInner::StartupTimeout(read_log_tail(log_path)).into());
+		}
+
+		sleep(STARTUP_RETRY_BACKOFF).await;
+	}
+}
+
+/// Read the trailing [`LOG_TAIL_BYTES`] of `mongod.log`, for inclusion in startup errors.
+fn read_log_tail(log_path: &Path) -> String {
+	let Ok(contents) = std::fs::read(log_path) else {
+		return String::new();
+	};
+	let start = contents.len().saturating_sub(LOG_TAIL_BYTES);
+	String::from_utf8_lossy(&contents[start..]).into_owned()
+}
+
+/// Maximum time to wait for a freshly initiated replica set to elect a primary.
+const REPLICA_SET_INITIATE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Initiate a single-member replica set named `name` pointed at `host`, and
+/// wait until the instance reports itself as `PRIMARY`.
+///
+/// A standalone `mongod` cannot support multi-document transactions or
+/// change streams, since those require an oplog; `--replSet` plus
+/// `replSetInitiate` gives a throwaway single-host deployment that can.
+async fn initiate_replica_set(client: &mongodb::Client, name: &str, host: &str) -> Result<(), Error> {
+	client
+		.database("admin")
+		.run_command(
+			doc! {
+				"replSetInitiate": {
+					"_id": name,
+					"members": [{ "_id": 0, "host": host }],
+				},
+			},
+			None,
+		)
+		.await
+		.map_err(|e| ErrorInner::Connect(host.to_string(), e))?;
+
+	let deadline = std::time::Instant::now() + REPLICA_SET_INITIATE_TIMEOUT;
+	loop {
+		let hello = client
+			.database("admin")
+			.run_command(doc! { "hello": 1 }, None)
+			.await
+			.map_err(|e| ErrorInner::Connect(host.to_string(), e))?;
+
+		let is_primary = hello
+			.get_bool("isWritablePrimary")
+			.or_else(|_| hello.get_bool("ismaster"))
+			.unwrap_or(false);
+		if is_primary {
+			return Ok(());
+		}
+
+		if std::time::Instant::now() >= deadline {
+			return Err(ErrorInner::ReplicaSetTimeout(name.to_string()).into());
+		}
+
+		sleep(Duration::from_millis(100)).await;
+	}
+}
 /// Builder for customizing your [`TempMongo`] object.
 ///
 /// After configuring the desirec options, run [`TempMongoBuilder::spawn()`].
@@ -294,8 +907,27 @@ pub struct TempMongoBuilder {
 
 	/// The mongdb command to execute.
 	command: Option<OsString>,
+
+	/// Root user credentials to create on startup, if authentication is enabled.
+	auth: Option<AuthCredentials>,
+
+	/// Name of the single-member replica set to initiate on startup, if any.
+	replica_set: Option<String>,
+
+	/// Overall deadline for `mongod` to start accepting connections.
+	startup_timeout: Duration,
+
+	/// Overall deadline for `mongod` to exit on its own in [`TempMongo::shutdown_gracefully`].
+	shutdown_timeout: Duration,
+
+	/// How the server is stopped if the [`TempMongo`] is dropped without
+	/// calling [`TempMongo::shutdown_gracefully`] or [`TempMongo::kill_and_clean`].
+	drop_shutdown_mode: ShutdownMode,
 }
 
+/// Default overall deadline for `mongod` to exit on its own after a graceful shutdown request.
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
 impl TempMongoBuilder {
 	/// Create a new builder.
 	pub fn new() -> Self {
@@ -303,12 +935,87 @@ impl TempMongoBuilder {
 			parent_directory: None,
 			command: None,
 			clean_on_drop: true,
+			auth: None,
+			replica_set: None,
+			startup_timeout: DEFAULT_STARTUP_TIMEOUT,
+			shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+			drop_shutdown_mode: ShutdownMode::Kill,
 		}
 	}
 
+	/// Choose how the server is stopped if the [`TempMongo`] is simply dropped,
+	/// instead of calling [`TempMongo::shutdown_gracefully`] or
+	/// [`TempMongo::kill_and_clean`] explicitly.
+	///
+	/// Defaults to [`ShutdownMode::Kill`]. Set [`ShutdownMode::Terminate`] to
+	/// have `Drop` send `SIGTERM` and wait briefly for `mongod` to exit on its
+	/// own first, same as [`TempMongo::shutdown_gracefully`], falling back to
+	/// `SIGKILL` if it doesn't exit in time. This matters for scenarios like
+	/// reopening a preserved data directory, where an unclean `SIGKILL` stop
+	/// can leave it in a state `mongod` refuses to start back up from.
+	pub fn drop_shutdown_mode(mut self, mode: ShutdownMode) -> Self {
+		self.drop_shutdown_mode = mode;
+		self
+	}
+
+	/// Override the overall deadline for `mongod` to start accepting connections.
+	///
+	/// Defaults to [`DEFAULT_STARTUP_TIMEOUT`] (30 seconds).
+	pub fn startup_timeout(mut self, timeout: Duration) -> Self {
+		self.startup_timeout = timeout;
+		self
+	}
+
+	/// Override the overall deadline for `mongod` to exit on its own after [`TempMongo::shutdown_gracefully`] asks it to.
+	///
+	/// Defaults to [`DEFAULT_SHUTDOWN_TIMEOUT`] (10 seconds).
+	pub fn shutdown_timeout(mut self, timeout: Duration) -> Self {
+		self.shutdown_timeout = timeout;
+		self
+	}
+
+	/// Launch `mongod` as a single-member replica set named `name`.
+	///
+	/// A standalone `mongod` has no oplog, so it cannot run multi-document
+	/// transactions or change streams. This starts `mongod --replSet <name>`,
+	/// runs `replSetInitiate` with a config pointing at the instance's own
+	/// host, and waits until it reports itself as `PRIMARY` before
+	/// [`Self::spawn()`] returns, which unlocks `client.start_session()` and
+	/// `Collection::watch()` in tests.
+	pub fn replica_set(mut self, name: impl Into<String>) -> Self {
+		self.replica_set = Some(name.into());
+		self
+	}
+
+	/// Launch `mongod` with `--auth` and create a root user with the given credentials.
+	///
+	/// The root user is created on first startup via the "localhost exception":
+	/// `mongod` is connected to once with no credential to run `db.createUser`
+	/// against the `admin` database (or whichever database [`Self::auth_source`]
+	/// selects), after which the stored client is rebuilt with a
+	/// [`Credential`] so ordinary connections require authentication.
+	pub fn with_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+		self.auth = Some(AuthCredentials {
+			username: username.into(),
+			password: password.into(),
+			auth_source: "admin".to_string(),
+		});
+		self
+	}
+
+	/// Override the database the root user is created in and authenticates against.
+	///
+	/// Defaults to `admin`. Has no effect unless called after [`Self::with_auth`].
+	pub fn auth_source(mut self, auth_source: impl Into<String>) -> Self {
+		if let Some(auth) = &mut self.auth {
+			auth.auth_source = auth_source.into();
+		}
+		self
+	}
+
 	/// Spawn the MongoDB server and connect to it.
 	pub async fn spawn(&self) -> Result<TempMongo, Error> {
-		TempMongo::from_builder(self).await
+		TempMongo::from_builder(self, None).await
 	}
 
 	/// Enable or disable cleaning of the temporary state directory when the [`TempMongo`] object is dropped.
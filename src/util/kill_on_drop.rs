@@ -1,15 +1,44 @@
 use std::process::Child;
+use std::time::Duration;
+
+/// How a [`KillOnDrop`] stops its child process when dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownMode {
+    /// Send `SIGKILL` (or the Windows equivalent) immediately.
+    Kill,
+    /// Send `SIGTERM` first (see [`KillOnDrop::terminate`]), waiting up to
+    /// [`DROP_TERMINATE_TIMEOUT`] for the process to exit on its own before
+    /// falling back to `SIGKILL`.
+    Terminate,
+}
+
+/// How long [`Drop`] waits for the process to exit after a `SIGTERM`, when
+/// [`ShutdownMode::Terminate`] is set.
+const DROP_TERMINATE_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Simple wrapper around [`std::process::Child`] that kills the process when dropped.
 pub struct KillOnDrop {
     /// The wrapped child process.
     child: Child,
+    /// How [`Drop`] should stop `child`.
+    shutdown_mode: ShutdownMode,
 }
 
 impl KillOnDrop {
     /// Wrap an existing [`std::process:Child`] object.
+    ///
+    /// Defaults to [`ShutdownMode::Kill`]; use [`Self::set_shutdown_mode`] to
+    /// request a graceful stop on drop instead.
     pub fn new(child: Child) -> Self {
-        Self { child }
+        Self {
+            child,
+            shutdown_mode: ShutdownMode::Kill,
+        }
+    }
+
+    /// Set how [`Drop`] should stop the child process.
+    pub fn set_shutdown_mode(&mut self, shutdown_mode: ShutdownMode) {
+        self.shutdown_mode = shutdown_mode;
     }
 
     /// Get the PID of the child process.
@@ -21,10 +50,45 @@ impl KillOnDrop {
     pub fn kill(&mut self) -> std::io::Result<()> {
         self.child.kill()
     }
+
+    /// Ask the child process to terminate gracefully.
+    ///
+    /// On Unix, this sends `SIGTERM` instead of the `SIGKILL` that [`Self::kill`]
+    /// sends, giving the process a chance to shut down cleanly. Windows has no
+    /// equivalent signal, so this falls back to [`Self::kill`] there.
+    #[cfg(unix)]
+    pub fn terminate(&mut self) -> std::io::Result<()> {
+        let pid = nix::unistd::Pid::from_raw(self.child.id() as i32);
+        nix::sys::signal::kill(pid, nix::sys::signal::Signal::SIGTERM)
+            .map_err(|e| std::io::Error::from_raw_os_error(e as i32))
+    }
+
+    /// Ask the child process to terminate gracefully.
+    ///
+    /// Windows has no `SIGTERM` equivalent, so this falls back to [`Self::kill`].
+    #[cfg(windows)]
+    pub fn terminate(&mut self) -> std::io::Result<()> {
+        self.kill()
+    }
+
+    /// Check, without blocking, whether the child process has already exited.
+    pub fn try_wait(&mut self) -> std::io::Result<Option<std::process::ExitStatus>> {
+        self.child.try_wait()
+    }
 }
 
 impl Drop for KillOnDrop {
     fn drop(&mut self) {
+        if self.shutdown_mode == ShutdownMode::Terminate && self.terminate().is_ok() {
+            let deadline = std::time::Instant::now() + DROP_TERMINATE_TIMEOUT;
+            while std::time::Instant::now() < deadline {
+                if matches!(self.child.try_wait(), Ok(Some(_))) {
+                    return;
+                }
+                std::thread::sleep(Duration::from_millis(20));
+            }
+        }
+
         self.child.kill().ok();
         self.child.wait().ok();
     }
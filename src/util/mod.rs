@@ -2,11 +2,12 @@ mod temp_dir;
 pub use temp_dir::TempDir;
 
 mod kill_on_drop;
-pub use kill_on_drop::KillOnDrop;
+pub use kill_on_drop::{KillOnDrop, ShutdownMode};
 
 mod port_finder;
 pub use port_finder::PortGenerator;
 
-mod data_seeder;
+mod seed_data;
+pub use seed_data::{ColumnType, CsvOptions, SeedData};
 
-pub use data_seeder::DataSeeder;
+pub(crate) mod runtime;
@@ -0,0 +1,43 @@
+//! Runtime-agnostic helpers, selected by the `tokio-runtime` / `async-std-runtime` feature flags.
+//!
+//! This crate's own async code (startup retries, shutdown waits, ...) goes
+//! through here instead of calling `tokio`/`async-std` directly, so it keeps
+//! working regardless of which runtime feature the caller enables.
+//! [`crate::TempMongo::run_with_timeout`] is the exception: it calls
+//! `tokio::spawn` and `tokio::time::timeout` directly, so it needs a tokio runtime.
+
+use std::time::Duration;
+
+/// Sleep for `duration` on whichever runtime is enabled.
+#[cfg(feature = "tokio-runtime")]
+pub async fn sleep(duration: Duration) {
+	tokio::time::sleep(duration).await;
+}
+
+/// Sleep for `duration` on whichever runtime is enabled.
+#[cfg(feature = "async-std-runtime")]
+pub async fn sleep(duration: Duration) {
+	async_std::task::sleep(duration).await;
+}
+
+/// Run a blocking closure on a thread where blocking is allowed, on whichever runtime is enabled.
+#[cfg(feature = "tokio-runtime")]
+pub async fn spawn_blocking<F, T>(f: F) -> T
+where
+	F: FnOnce() -> T + Send + 'static,
+	T: Send + 'static,
+{
+	tokio::task::spawn_blocking(f)
+		.await
+		.expect("blocking task panicked")
+}
+
+/// Run a blocking closure on a thread where blocking is allowed, on whichever runtime is enabled.
+#[cfg(feature = "async-std-runtime")]
+pub async fn spawn_blocking<F, T>(f: F) -> T
+where
+	F: FnOnce() -> T + Send + 'static,
+	T: Send + 'static,
+{
+	async_std::task::spawn_blocking(f).await
+}
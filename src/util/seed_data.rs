@@ -1,9 +1,37 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 use mongodb::{Client, bson::Document};
+use mongodb::options::InsertManyOptions;
 use calamine::{open_workbook_auto, DataType, Reader};
 
+/// Maximum number of documents sent in a single `insertMany` command.
+///
+/// MongoDB caps commands at 48MB; batching keeps large fixtures comfortably
+/// under that limit regardless of document size.
+const INSERT_MANY_BATCH_SIZE: usize = 1000;
+
+/// An explicit type to coerce a CSV column to, overriding inference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    /// Coerce the column to `Bson::Int64`.
+    Int,
+    /// Coerce the column to `Bson::Double`.
+    Float,
+    /// Coerce the column to `Bson::Boolean`.
+    Bool,
+    /// Keep the column as `Bson::String`.
+    String,
+}
+
+/// Options controlling [`SeedData::from_csv`]'s type inference.
+#[derive(Debug, Default, Clone)]
+pub struct CsvOptions {
+    /// Force specific columns (by header name) to a given type instead of
+    /// relying on the default `i64` -> `f64` -> `bool` -> `String` inference.
+    pub column_types: std::collections::HashMap<String, ColumnType>,
+}
+
 /// Data seed options for mongodb instance
 /// 
 /// The database_name and collection_name are used to specify the database and collection to seed the data into
@@ -70,7 +98,7 @@ impl SeedData {
     }
 
     
-    /// Reads and parses a seed data file into a `SeedData` instance.
+    /// Reads a JSON file holding a plain array of documents.
     ///
     /// # Arguments
     ///
@@ -78,11 +106,32 @@ impl SeedData {
     ///
     /// # Errors
     ///
-    /// Returns an error if the file cannot be read or if the file content is not valid JSON.
-    pub fn from_file(&self, file_path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+    /// Returns an error if the file cannot be read or if the file content is not a valid JSON array of documents.
+    pub fn from_file(&self, file_path: &Path) -> Result<Vec<Document>, Box<dyn std::error::Error>> {
         let file_content = fs::read_to_string(file_path)?;
-        let seed_data: Self = serde_json::from_str(&file_content)?;
-        Ok(seed_data)
+        let documents: Vec<Document> = serde_json::from_str(&file_content)?;
+        Ok(documents)
+    }
+
+    /// Reads a JSON file holding a plain array of documents directly into a `SeedData` for one collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_name` - The name of the database to seed.
+    /// * `collection_name` - The name of the collection to seed.
+    /// * `file_path` - A reference to the path of the JSON file to read the documents from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or if the file content is not a valid JSON array of documents.
+    pub fn from_json_file(
+        &self,
+        database_name: &str,
+        collection_name: &str,
+        file_path: &Path,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let documents = self.from_file(file_path)?;
+        Ok(self.new_in(database_name, collection_name, documents))
     }
 
     /// Reads an Excel file and returns a vector of MongoDB documents to be used for seeding.
@@ -131,8 +180,107 @@ impl SeedData {
         Ok(documents)
     }
 
+    /// Recursively walks a fixtures directory and builds one `SeedData` per file.
+    ///
+    /// The database name is taken from the file's parent directory name and
+    /// the collection name from its file stem, so a tree laid out as
+    /// `fixtures/<database>/<collection>.json` seeds `<database>.<collection>`.
+    /// The format is auto-detected from each file's extension: `.json` is
+    /// read via [`Self::from_file`], `.xlsx`/`.xls` via [`Self::from_excel`]
+    /// (using that workbook's first sheet). Files with any other extension
+    /// are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root of the fixtures directory tree to walk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a fixture file cannot be read or parsed.
+    pub fn from_directory(&self, root: &Path) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut seeds = Vec::new();
+
+        for entry in walkdir::WalkDir::new(root) {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let path = entry.path();
+            let extension = match path.extension().and_then(|e| e.to_str()) {
+                Some(extension) => extension.to_ascii_lowercase(),
+                None => continue,
+            };
+
+            let database_name = match path.parent().and_then(|p| p.file_name()).and_then(|n| n.to_str()) {
+                Some(name) => name.to_string(),
+                None => continue,
+            };
+            let collection_name = match path.file_stem().and_then(|n| n.to_str()) {
+                Some(name) => name.to_string(),
+                None => continue,
+            };
+
+            let documents = match extension.as_str() {
+                "json" => self.from_file(path)?,
+                "xlsx" | "xls" => {
+                    let sheet = Self::first_sheet_name(path)?;
+                    self.from_excel(path, &sheet)?
+                }
+                _ => continue,
+            };
+
+            seeds.push(self.new_in(&database_name, &collection_name, documents));
+        }
+
+        Ok(seeds)
+    }
+
+    /// Build a `SeedData` from ordinary domain structs/enums instead of raw `bson::Document`s.
+    ///
+    /// Each item is serialized to a `Document` via [`mongodb::bson::to_document`],
+    /// so fixtures can be defined as the same structs/enums the code under
+    /// test already works with, keeping fixtures in sync with those types.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_name` - The name of the database to seed.
+    /// * `collection_name` - The name of the collection to seed.
+    /// * `items` - The typed items to serialize and seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any item fails to serialize to a BSON document.
+    pub fn from_typed<T: Serialize>(
+        &self,
+        database_name: &str,
+        collection_name: &str,
+        items: &[T],
+    ) -> Result<Self, mongodb::bson::ser::Error> {
+        let documents = items
+            .iter()
+            .map(mongodb::bson::to_document)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(self.new_in(database_name, collection_name, documents))
+    }
+
+    /// Get the name of the first sheet in an Excel workbook.
+    fn first_sheet_name(file_path: &Path) -> Result<String, Box<dyn std::error::Error>> {
+        let workbook = open_workbook_auto(file_path)?;
+        workbook
+            .sheet_names()
+            .first()
+            .cloned()
+            .ok_or_else(|| "Workbook has no sheets".into())
+    }
+
     /// Seeds the specified MongoDB collection with the provided documents.
     ///
+    /// Documents are inserted with a single `insertMany` per batch of
+    /// [`INSERT_MANY_BATCH_SIZE`] documents, rather than one `insertOne` per
+    /// document, and inserts are ordered so a failure stops at the first bad
+    /// document. Use [`Self::seed_with_options`] to allow unordered inserts.
+    ///
     /// # Arguments
     ///
     /// * `client` - A reference to the MongoDB client to use for inserting documents.
@@ -141,13 +289,132 @@ impl SeedData {
     ///
     /// Returns an error if any MongoDB operation fails during the seeding process.
     pub async fn seed(&self, client: &Client) -> mongodb::error::Result<()> {
+        self.seed_with_options(client, InsertManyOptions::builder().ordered(true).build())
+            .await
+    }
+
+    /// Seeds the specified MongoDB collection, with control over insert ordering.
+    ///
+    /// Set `options.ordered` to `false` to let MongoDB continue inserting the
+    /// rest of a batch after a failed document, trading strict ordering for
+    /// throughput.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A reference to the MongoDB client to use for inserting documents.
+    /// * `options` - Options forwarded to each batch's `insertMany` command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any MongoDB operation fails during the seeding process.
+    pub async fn seed_with_options(
+        &self,
+        client: &Client,
+        options: InsertManyOptions,
+    ) -> mongodb::error::Result<()> {
+        if self.documents.is_empty() {
+            return Ok(());
+        }
+
         let collection = client.database(&self.database_name).collection(&self.collection_name);
-        for document in &self.documents {
-            collection.insert_one(document.clone(), None).await?;
+        for batch in self.documents.chunks(INSERT_MANY_BATCH_SIZE) {
+            collection.insert_many(batch, options.clone()).await?;
         }
         Ok(())
     }
 
+    /// Seed many independent collections concurrently.
+    ///
+    /// Each [`SeedData`] drives its own collection via [`Self::seed`], and all
+    /// of them run concurrently rather than one after another, which matters
+    /// once fixtures span several collections.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered by any of the concurrent seeds.
+    pub async fn load_many(seeds: &[SeedData], client: &Client) -> mongodb::error::Result<()> {
+        let seeding = seeds.iter().map(|seed| seed.seed(client));
+        futures_util::future::try_join_all(seeding).await?;
+        Ok(())
+    }
+
+    /// Reads a CSV file and returns a vector of MongoDB documents to be used for seeding.
+    ///
+    /// CSV cells are untyped strings, so each value is inferred as an `i64`,
+    /// then an `f64`, then a `bool` (`true`/`false`), falling back to a
+    /// string; `options.column_types` can override this inference per column.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - A reference to the path of the CSV file.
+    /// * `options` - Per-column type overrides for the inference step.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed as CSV.
+    pub fn from_csv(
+        &self,
+        file_path: &Path,
+        options: &CsvOptions,
+    ) -> Result<Vec<mongodb::bson::Document>, Box<dyn std::error::Error>> {
+        let mut reader = csv::Reader::from_path(file_path)?;
+        let headers: Vec<String> = reader.headers()?.iter().map(str::to_string).collect();
+
+        let mut documents = Vec::new();
+        for record in reader.records() {
+            let record = record?;
+            let mut document = mongodb::bson::doc! {};
+            for (header, cell) in headers.iter().zip(record.iter()) {
+                let bson_value = Self::infer_csv_value(header, cell, options);
+                document.insert(header.clone(), bson_value);
+            }
+            documents.push(document);
+        }
+
+        Ok(documents)
+    }
+
+    /// Infer the `Bson` value of a single CSV cell, honoring `options.column_types`.
+    fn infer_csv_value(header: &str, cell: &str, options: &CsvOptions) -> mongodb::bson::Bson {
+        if let Some(column_type) = options.column_types.get(header) {
+            return Self::coerce_csv_value(cell, *column_type);
+        }
+
+        if let Ok(value) = cell.parse::<i64>() {
+            return mongodb::bson::Bson::Int64(value);
+        }
+        if let Ok(value) = cell.parse::<f64>() {
+            return mongodb::bson::Bson::Double(value);
+        }
+        match cell {
+            "true" => mongodb::bson::Bson::Boolean(true),
+            "false" => mongodb::bson::Bson::Boolean(false),
+            _ => mongodb::bson::Bson::String(cell.to_string()),
+        }
+    }
+
+    /// Coerce a CSV cell to an explicitly requested [`ColumnType`].
+    ///
+    /// Falls back to `Bson::String` if the cell doesn't parse as the
+    /// requested type, rather than failing the whole seed.
+    fn coerce_csv_value(cell: &str, column_type: ColumnType) -> mongodb::bson::Bson {
+        match column_type {
+            ColumnType::Int => cell
+                .parse::<i64>()
+                .map(mongodb::bson::Bson::Int64)
+                .unwrap_or_else(|_| mongodb::bson::Bson::String(cell.to_string())),
+            ColumnType::Float => cell
+                .parse::<f64>()
+                .map(mongodb::bson::Bson::Double)
+                .unwrap_or_else(|_| mongodb::bson::Bson::String(cell.to_string())),
+            ColumnType::Bool => cell
+                .parse::<bool>()
+                .map(mongodb::bson::Bson::Boolean)
+                .unwrap_or_else(|_| mongodb::bson::Bson::String(cell.to_string())),
+            ColumnType::String => mongodb::bson::Bson::String(cell.to_string()),
+        }
+    }
+
     /// Converts a row of Excel data to a MongoDB document using provided headers.
     ///
     /// # Arguments
@@ -175,6 +442,13 @@ impl SeedData {
             DataType::Bool(value) => {
                 mongodb::bson::Bson::Boolean(*value)
             },
+            DataType::DateTime(_) => match cell.as_datetime() {
+                Some(naive) => {
+                    let millis = naive.and_utc().timestamp_millis();
+                    mongodb::bson::Bson::DateTime(mongodb::bson::DateTime::from_millis(millis))
+                }
+                None => continue,
+            },
             // Handle other DataType variants as needed
             _ => continue, // Skip unknown or empty types
         };
@@ -184,4 +458,54 @@ impl SeedData {
 
     document
 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infer_csv_value_detects_int_float_bool_and_string() {
+        let options = CsvOptions::default();
+        assert_eq!(SeedData::infer_csv_value("n", "42", &options), mongodb::bson::Bson::Int64(42));
+        assert_eq!(SeedData::infer_csv_value("n", "4.5", &options), mongodb::bson::Bson::Double(4.5));
+        assert_eq!(SeedData::infer_csv_value("n", "true", &options), mongodb::bson::Bson::Boolean(true));
+        assert_eq!(SeedData::infer_csv_value("n", "false", &options), mongodb::bson::Bson::Boolean(false));
+        assert_eq!(
+            SeedData::infer_csv_value("n", "hello", &options),
+            mongodb::bson::Bson::String("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn infer_csv_value_honors_column_type_override() {
+        let mut options = CsvOptions::default();
+        options.column_types.insert("n".to_string(), ColumnType::String);
+        assert_eq!(
+            SeedData::infer_csv_value("n", "42", &options),
+            mongodb::bson::Bson::String("42".to_string())
+        );
+    }
+
+    #[test]
+    fn coerce_csv_value_converts_to_requested_type() {
+        assert_eq!(SeedData::coerce_csv_value("7", ColumnType::Int), mongodb::bson::Bson::Int64(7));
+        assert_eq!(SeedData::coerce_csv_value("7.5", ColumnType::Float), mongodb::bson::Bson::Double(7.5));
+        assert_eq!(
+            SeedData::coerce_csv_value("true", ColumnType::Bool),
+            mongodb::bson::Bson::Boolean(true)
+        );
+        assert_eq!(
+            SeedData::coerce_csv_value("x", ColumnType::String),
+            mongodb::bson::Bson::String("x".to_string())
+        );
+    }
+
+    #[test]
+    fn coerce_csv_value_falls_back_to_string_on_parse_failure() {
+        assert_eq!(
+            SeedData::coerce_csv_value("not-a-number", ColumnType::Int),
+            mongodb::bson::Bson::String("not-a-number".to_string())
+        );
+    }
 }
\ No newline at end of file
@@ -2,7 +2,16 @@ use assert2::{assert, let_assert};
 use futures_util::stream::TryStreamExt;
 use mongodb::bson::{doc, Document};
 
-use temp_mongo::TempMongo;
+use temp_mongo::{cache_digest, TempMongo};
+
+#[test]
+fn cache_digest_is_stable_and_order_sensitive() {
+    let alice = doc! { "name": "Alice", "age": 30 };
+    let bob = doc! { "name": "Bob", "age": 25 };
+
+    assert_eq!(cache_digest(&[alice.clone(), bob.clone()]), cache_digest(&[alice.clone(), bob.clone()]));
+    assert!(cache_digest(&[alice.clone(), bob.clone()]) != cache_digest(&[bob, alice]));
+}
 
 //Testing if we can upload a normal document and retrieve it from the temporary database
 //In addition to this we are also testing if the database is truly erased from the system by making use of kill_and_clean